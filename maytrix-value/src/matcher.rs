@@ -0,0 +1,319 @@
+use std::collections::VecDeque;
+
+use rustc_hash::FxHashMap;
+
+use crate::Symbol;
+
+/// A single occurrence of a [`Symbol`] found by [`SymbolMatcher::scan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The symbol that matched.
+    pub symbol: Symbol,
+    /// Byte offset of the first byte of the match in the scanned haystack.
+    pub start: usize,
+    /// Byte offset one past the last byte of the match in the scanned haystack.
+    pub end: usize,
+}
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    goto: FxHashMap<u8, usize>,
+    fail: usize,
+    /// Indices into `SymbolMatcher::symbols` terminal at this node, merged
+    /// along failure links so a node reports its own pattern plus any
+    /// pattern reachable by following `fail`.
+    output: Vec<u32>,
+}
+
+/// An Aho-Corasick automaton over a fixed set of [`Symbol`]s.
+///
+/// Build once from every `Symbol` you want to recognize, then call
+/// [`SymbolMatcher::scan`] to find every occurrence of any of them in a
+/// haystack in a single linear pass, regardless of how many symbols were
+/// registered.
+///
+/// # Examples
+///
+/// ```
+/// use maytrix_value::{Symbol, SymbolMatcher};
+///
+/// let matcher = SymbolMatcher::new([
+///     Symbol::try_new("he").unwrap(),
+///     Symbol::try_new("she").unwrap(),
+///     Symbol::try_new("his").unwrap(),
+///     Symbol::try_new("hers").unwrap(),
+/// ]);
+///
+/// let found: Vec<&str> = matcher.scan("ushers").map(|m| m.symbol.as_str()).collect();
+/// assert_eq!(found, vec!["she", "he", "hers"]);
+/// ```
+#[derive(Debug)]
+pub struct SymbolMatcher {
+    symbols: Vec<Symbol>,
+    nodes: Vec<TrieNode>,
+    require_word_boundaries: bool,
+}
+
+impl SymbolMatcher {
+    /// Builds a matcher recognizing every symbol in `symbols`.
+    pub fn new<I: IntoIterator<Item = Symbol>>(symbols: I) -> Self {
+        Self::build(symbols, false)
+    }
+
+    /// Builds a matcher that only reports matches falling on word
+    /// boundaries, i.e. not immediately preceded or followed by an
+    /// `[A-Za-z0-9_]` byte. This stops `alpha` from matching inside
+    /// `alphabet`.
+    pub fn new_with_word_boundaries<I: IntoIterator<Item = Symbol>>(symbols: I) -> Self {
+        Self::build(symbols, true)
+    }
+
+    fn build<I: IntoIterator<Item = Symbol>>(symbols: I, require_word_boundaries: bool) -> Self {
+        let symbols: Vec<Symbol> = symbols.into_iter().collect();
+        let mut nodes = vec![TrieNode::default()];
+
+        for (idx, symbol) in symbols.iter().enumerate() {
+            let mut node = 0;
+            for &byte in symbol.as_str().as_bytes() {
+                node = match nodes[node].goto.get(&byte) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(TrieNode::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].goto.insert(byte, next);
+                        next
+                    }
+                };
+            }
+            nodes[node].output.push(idx as u32);
+        }
+
+        let mut order = Vec::new();
+        let mut queue = VecDeque::new();
+        let root_children: Vec<usize> = nodes[0].goto.values().copied().collect();
+        for child in root_children {
+            nodes[child].fail = 0;
+            queue.push_back(child);
+        }
+        while let Some(parent) = queue.pop_front() {
+            order.push(parent);
+            let children: Vec<(u8, usize)> =
+                nodes[parent].goto.iter().map(|(&b, &v)| (b, v)).collect();
+            for (byte, child) in children {
+                queue.push_back(child);
+                let target = Self::follow_fail(&nodes, nodes[parent].fail, byte);
+                nodes[child].fail = if target == child { 0 } else { target };
+            }
+        }
+        for node in order {
+            let fail = nodes[node].fail;
+            let inherited = nodes[fail].output.clone();
+            nodes[node].output.extend(inherited);
+        }
+
+        Self {
+            symbols,
+            nodes,
+            require_word_boundaries,
+        }
+    }
+
+    /// Follows `goto` links from `node`, falling back through failure
+    /// links (and finally the root) until a transition on `byte` is found.
+    fn follow_fail(nodes: &[TrieNode], mut node: usize, byte: u8) -> usize {
+        loop {
+            if let Some(&next) = nodes[node].goto.get(&byte) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = nodes[node].fail;
+        }
+    }
+
+    fn step(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[node].goto.get(&byte) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    fn is_word_boundary(&self, bytes: &[u8], start: usize, end: usize) -> bool {
+        fn is_word_byte(b: u8) -> bool {
+            b.is_ascii_alphanumeric() || b == b'_'
+        }
+        let before_ok = start == 0 || !is_word_byte(bytes[start - 1]);
+        let after_ok = end == bytes.len() || !is_word_byte(bytes[end]);
+        before_ok && after_ok
+    }
+
+    /// Scans `haystack` for every occurrence of any registered symbol,
+    /// walking the text one byte at a time and emitting a [`Match`] for
+    /// every terminal hit, in the order they end in the haystack. The same
+    /// byte range can be reported more than once if more than one
+    /// registered symbol matches there (e.g. both `"he"` and `"she"`
+    /// ending at the same position).
+    pub fn scan<'a>(&'a self, haystack: &'a str) -> impl Iterator<Item = Match> + 'a {
+        Scan {
+            matcher: self,
+            haystack,
+            pos: 0,
+            node: 0,
+            pending: Vec::new(),
+            pending_next: 0,
+            pending_end: 0,
+        }
+    }
+
+    /// Like [`SymbolMatcher::scan`], but resolves overlapping matches to a
+    /// non-overlapping leftmost-longest set: scanning left to right, the
+    /// longest match starting at each position wins and the scan resumes
+    /// immediately after it.
+    pub fn scan_leftmost_longest<'a>(
+        &'a self,
+        haystack: &'a str,
+    ) -> impl Iterator<Item = Match> + 'a {
+        let mut matches: Vec<Match> = self.scan(haystack).collect();
+        matches.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+        let mut accepted = Vec::new();
+        let mut next_allowed = 0usize;
+        for m in matches {
+            if m.start < next_allowed {
+                continue;
+            }
+            next_allowed = m.end;
+            accepted.push(m);
+        }
+        accepted.into_iter()
+    }
+}
+
+struct Scan<'a> {
+    matcher: &'a SymbolMatcher,
+    haystack: &'a str,
+    pos: usize,
+    node: usize,
+    pending: Vec<u32>,
+    pending_next: usize,
+    pending_end: usize,
+}
+
+impl<'a> Iterator for Scan<'a> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            if let Some(&idx) = self.pending.get(self.pending_next) {
+                self.pending_next += 1;
+                let symbol = self.matcher.symbols[idx as usize];
+                let len = symbol.as_str().len();
+                let start = self.pending_end - len;
+                let end = self.pending_end;
+                if self.matcher.require_word_boundaries
+                    && !self
+                        .matcher
+                        .is_word_boundary(self.haystack.as_bytes(), start, end)
+                {
+                    continue;
+                }
+                return Some(Match { symbol, start, end });
+            }
+
+            if self.pos >= self.haystack.len() {
+                return None;
+            }
+            let byte = self.haystack.as_bytes()[self.pos];
+            self.node = self.matcher.step(self.node, byte);
+            self.pos += 1;
+            if !self.matcher.nodes[self.node].output.is_empty() {
+                self.pending = self.matcher.nodes[self.node].output.clone();
+                self.pending_next = 0;
+                self.pending_end = self.pos;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sym(s: &str) -> Symbol {
+        Symbol::try_new(s).unwrap()
+    }
+
+    fn texts(matcher: &SymbolMatcher, haystack: &str) -> Vec<(String, usize, usize)> {
+        matcher
+            .scan(haystack)
+            .map(|m| (m.symbol.as_str().to_string(), m.start, m.end))
+            .collect()
+    }
+
+    #[test]
+    fn finds_every_occurrence_of_every_pattern() {
+        let matcher = SymbolMatcher::new([sym("he"), sym("she"), sym("his"), sym("hers")]);
+        let found = texts(&matcher, "ushers");
+        assert_eq!(
+            found,
+            vec![
+                ("she".to_string(), 1, 4),
+                ("he".to_string(), 2, 4),
+                ("hers".to_string(), 2, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_matches_on_unrelated_text() {
+        let matcher = SymbolMatcher::new([sym("foo"), sym("bar")]);
+        assert_eq!(texts(&matcher, "completely_unrelated"), vec![]);
+    }
+
+    #[test]
+    fn overlapping_patterns_all_reported_by_scan() {
+        let matcher = SymbolMatcher::new([sym("a"), sym("ab"), sym("b")]);
+        let found = texts(&matcher, "ab");
+        assert_eq!(
+            found,
+            vec![
+                ("a".to_string(), 0, 1),
+                ("ab".to_string(), 0, 2),
+                ("b".to_string(), 1, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_leftmost_longest_picks_longest_non_overlapping() {
+        let matcher = SymbolMatcher::new([sym("a"), sym("ab"), sym("b")]);
+        let found: Vec<&str> = matcher
+            .scan_leftmost_longest("ab")
+            .map(|m| m.symbol.as_str())
+            .collect();
+        assert_eq!(found, vec!["ab"]);
+    }
+
+    #[test]
+    fn word_boundaries_reject_matches_inside_longer_words() {
+        let matcher = SymbolMatcher::new_with_word_boundaries([sym("alpha")]);
+        assert_eq!(texts(&matcher, "alphabet"), vec![]);
+        assert_eq!(texts(&matcher, "alpha"), vec![("alpha".to_string(), 0, 5)]);
+        assert_eq!(
+            texts(&matcher, "the alpha version"),
+            vec![("alpha".to_string(), 4, 9)]
+        );
+    }
+
+    #[test]
+    fn empty_symbol_set_never_matches() {
+        let matcher = SymbolMatcher::new(std::iter::empty());
+        assert_eq!(texts(&matcher, "anything"), vec![]);
+    }
+}
@@ -0,0 +1,277 @@
+use core::marker::PhantomData;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// A named validation ruleset for [`crate::Symbol`].
+///
+/// Implementing this trait lets `Symbol<S>` be built under a naming
+/// convention other than the default [`LowerSnake`] rule, while keeping the
+/// same interning, equality, and gensym machinery. `NAME` and [`pattern`]
+/// are surfaced on [`crate::SymbolError`] so a rejected value's message says
+/// which ruleset it failed and what that ruleset requires.
+///
+/// `pattern` is a method rather than a plain const so a composed spec (see
+/// [`MaxLen`], [`AsciiOnly`]) can describe its own extra constraint instead
+/// of just forwarding the wrapped spec's pattern, which would otherwise
+/// under-report what actually failed.
+///
+/// [`pattern`]: SymbolSpec::pattern
+pub trait SymbolSpec: 'static {
+    /// Short, human-readable name of this ruleset, e.g. `"lower_snake"`.
+    const NAME: &'static str;
+    /// The regex-equivalent pattern this ruleset enforces.
+    fn pattern() -> &'static str;
+    /// Returns true if `s` satisfies this ruleset.
+    fn is_valid(s: &str) -> bool;
+}
+
+/// The default ruleset: lowercase ASCII snake_case, `^[a-z][a-z0-9_]*$`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LowerSnake;
+
+impl SymbolSpec for LowerSnake {
+    const NAME: &'static str = "lower_snake";
+
+    fn pattern() -> &'static str {
+        "^[a-z][a-z0-9_]*$"
+    }
+
+    fn is_valid(s: &str) -> bool {
+        let mut chars = s.chars();
+        match chars.next() {
+            Some(first) if first.is_ascii_lowercase() => {}
+            _ => return false,
+        }
+        chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+}
+
+/// UpperCamelCase (a.k.a. PascalCase) type names, `^[A-Z][A-Za-z0-9]*$`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct UpperCamel;
+
+impl SymbolSpec for UpperCamel {
+    const NAME: &'static str = "upper_camel";
+
+    fn pattern() -> &'static str {
+        "^[A-Z][A-Za-z0-9]*$"
+    }
+
+    fn is_valid(s: &str) -> bool {
+        let mut chars = s.chars();
+        match chars.next() {
+            Some(first) if first.is_ascii_uppercase() => {}
+            _ => return false,
+        }
+        chars.all(|c| c.is_ascii_alphanumeric())
+    }
+}
+
+/// Lowercase kebab-case keys, `^[a-z][a-z0-9]*(-[a-z0-9]+)*$`: no leading,
+/// trailing, or doubled hyphens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KebabCase;
+
+impl SymbolSpec for KebabCase {
+    const NAME: &'static str = "kebab_case";
+
+    fn pattern() -> &'static str {
+        "^[a-z][a-z0-9]*(-[a-z0-9]+)*$"
+    }
+
+    fn is_valid(s: &str) -> bool {
+        let bytes = s.as_bytes();
+        if bytes.is_empty() || !bytes[0].is_ascii_lowercase() {
+            return false;
+        }
+        let mut prev_hyphen = false;
+        for &b in &bytes[1..] {
+            if b == b'-' {
+                if prev_hyphen {
+                    return false;
+                }
+                prev_hyphen = true;
+            } else if b.is_ascii_lowercase() || b.is_ascii_digit() {
+                prev_hyphen = false;
+            } else {
+                return false;
+            }
+        }
+        !prev_hyphen
+    }
+}
+
+/// Numeric-leading codes, `^[0-9][a-z0-9_]*$` — otherwise the same grammar
+/// as [`LowerSnake`] but allowing (requiring) a digit first character.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NumericCode;
+
+impl SymbolSpec for NumericCode {
+    const NAME: &'static str = "numeric_code";
+
+    fn pattern() -> &'static str {
+        "^[0-9][a-z0-9_]*$"
+    }
+
+    fn is_valid(s: &str) -> bool {
+        let mut chars = s.chars();
+        match chars.next() {
+            Some(first) if first.is_ascii_digit() => {}
+            _ => return false,
+        }
+        chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+}
+
+/// Composes `S` with an additional cap of `N` `char`s.
+///
+/// # Examples
+///
+/// ```
+/// use maytrix_value::{MaxLen, LowerSnake, SymbolSpec};
+/// assert!(<MaxLen<4, LowerSnake>>::is_valid("abcd"));
+/// assert!(!<MaxLen<4, LowerSnake>>::is_valid("abcde"));
+/// ```
+pub struct MaxLen<const N: usize, S>(PhantomData<S>);
+
+impl<const N: usize, S> Default for MaxLen<N, S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<const N: usize, S: SymbolSpec> SymbolSpec for MaxLen<N, S> {
+    const NAME: &'static str = "max_len";
+
+    fn pattern() -> &'static str {
+        // A `static` in a generic fn is shared by every monomorphization, so
+        // the cache is keyed by `TypeId` to hold one entry per `(N, S)`,
+        // leaked like the symbol interner leaks its strings. `S::pattern()`
+        // runs outside the lock, since `S` may itself be a `MaxLen`.
+        static CACHE: OnceLock<Mutex<HashMap<TypeId, &'static str>>> = OnceLock::new();
+        let cache = CACHE.get_or_init(Default::default);
+        if let Some(&pattern) = cache.lock().unwrap().get(&TypeId::of::<Self>()) {
+            return pattern;
+        }
+        let formatted = format!("{} (<= {N} chars)", S::pattern());
+        let mut cache = cache.lock().unwrap();
+        *cache
+            .entry(TypeId::of::<Self>())
+            .or_insert_with(|| Box::leak(formatted.into_boxed_str()))
+    }
+
+    fn is_valid(s: &str) -> bool {
+        s.chars().count() <= N && S::is_valid(s)
+    }
+}
+
+/// Composes `S` with a restriction to the ASCII Unicode category, rejecting
+/// any value containing a non-ASCII `char`.
+///
+/// # Examples
+///
+/// ```
+/// use maytrix_value::{AsciiOnly, LowerSnake, SymbolSpec};
+/// assert!(<AsciiOnly<LowerSnake>>::is_valid("abc"));
+/// assert!(!<AsciiOnly<LowerSnake>>::is_valid("abc\u{00e9}"));
+/// ```
+pub struct AsciiOnly<S>(PhantomData<S>);
+
+impl<S> Default for AsciiOnly<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S: SymbolSpec> SymbolSpec for AsciiOnly<S> {
+    const NAME: &'static str = "ascii_only";
+
+    fn pattern() -> &'static str {
+        // Keyed by `TypeId` for the same reason as `MaxLen::pattern`.
+        static CACHE: OnceLock<Mutex<HashMap<TypeId, &'static str>>> = OnceLock::new();
+        let cache = CACHE.get_or_init(Default::default);
+        if let Some(&pattern) = cache.lock().unwrap().get(&TypeId::of::<Self>()) {
+            return pattern;
+        }
+        let formatted = format!("{} (ASCII only)", S::pattern());
+        let mut cache = cache.lock().unwrap();
+        *cache
+            .entry(TypeId::of::<Self>())
+            .or_insert_with(|| Box::leak(formatted.into_boxed_str()))
+    }
+
+    fn is_valid(s: &str) -> bool {
+        s.is_ascii() && S::is_valid(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_snake_matches_original_symbol_grammar() {
+        assert!(LowerSnake::is_valid("a0_b"));
+        assert!(!LowerSnake::is_valid("Nope"));
+        assert!(!LowerSnake::is_valid("_bad"));
+    }
+
+    #[test]
+    fn upper_camel_accepts_type_names() {
+        assert!(UpperCamel::is_valid("Foo"));
+        assert!(UpperCamel::is_valid("FooBar2"));
+        assert!(!UpperCamel::is_valid("foo"));
+        assert!(!UpperCamel::is_valid(""));
+        assert!(!UpperCamel::is_valid("Foo-Bar"));
+    }
+
+    #[test]
+    fn kebab_case_rejects_leading_trailing_and_double_hyphens() {
+        assert!(KebabCase::is_valid("foo-bar"));
+        assert!(KebabCase::is_valid("foo-bar-2"));
+        assert!(!KebabCase::is_valid("-foo"));
+        assert!(!KebabCase::is_valid("foo-"));
+        assert!(!KebabCase::is_valid("foo--bar"));
+        assert!(!KebabCase::is_valid("Foo-bar"));
+    }
+
+    #[test]
+    fn numeric_code_requires_leading_digit() {
+        assert!(NumericCode::is_valid("007_bond"));
+        assert!(!NumericCode::is_valid("bond_007"));
+        assert!(!NumericCode::is_valid(""));
+    }
+
+    #[test]
+    fn max_len_composes_with_an_inner_spec() {
+        assert!(<MaxLen<3, LowerSnake>>::is_valid("abc"));
+        assert!(!<MaxLen<3, LowerSnake>>::is_valid("abcd"));
+        assert!(!<MaxLen<3, LowerSnake>>::is_valid("ABC"));
+    }
+
+    #[test]
+    fn ascii_only_composes_with_an_inner_spec() {
+        assert!(<AsciiOnly<UpperCamel>>::is_valid("Foo"));
+        assert!(!<AsciiOnly<UpperCamel>>::is_valid("Fo\u{f6}"));
+    }
+
+    #[test]
+    fn max_len_pattern_reports_its_own_cap_not_just_the_inner_pattern() {
+        // "abcd" satisfies LowerSnake::pattern() outright, so a pattern that
+        // only echoed the inner spec would make this rejection look wrong.
+        assert!(LowerSnake::is_valid("abcd"));
+        assert!(!<MaxLen<3, LowerSnake>>::is_valid("abcd"));
+
+        let pattern = <MaxLen<3, LowerSnake>>::pattern();
+        assert!(pattern.contains(LowerSnake::pattern()));
+        assert!(pattern.contains('3'));
+    }
+
+    #[test]
+    fn ascii_only_pattern_reports_its_own_restriction_not_just_the_inner_pattern() {
+        let pattern = <AsciiOnly<UpperCamel>>::pattern();
+        assert!(pattern.contains(UpperCamel::pattern()));
+        assert!(pattern.to_lowercase().contains("ascii"));
+    }
+}
@@ -1,344 +1,707 @@
-/// A validated identifier following the pattern `^[a-z][a-z0-9_]*$`.
-///
-/// `Symbol` ensures its inner value is a well-formed, lowercase ASCII identifier
-/// commonly used for names, keys, or codes. It provides efficient comparison
-/// and map/set usage by implementing `Eq`, `Ord`, and `Hash` and supports
-/// borrowing as `&str`.
-///
-/// # Examples
-///
-/// Creating a valid `Symbol`:
-///
-/// ```
-/// use maytrix_value::Symbol;
-/// let sym = Symbol::try_new("alpha_1").unwrap();
-/// assert_eq!(sym.as_str(), "alpha_1");
-/// ```
-///
-/// Invalid values yield an error:
-///
-/// ```
-/// use maytrix_value::Symbol;
-/// assert!(Symbol::try_new("Bad-Name").is_err());
-/// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Symbol {
-    value: String,
-}
-
-impl Symbol {
-    /// Attempts to construct a `Symbol` from a string-like value.
-    ///
-    /// The input must match the regex `^[a-z][a-z0-9_]*$`.
-    ///
-    /// # Examples
-    ///
-    /// Successful creation:
-    /// ```
-    /// use maytrix_value::Symbol;
-    /// let s = Symbol::try_new("task1").unwrap();
-    /// assert_eq!(s, "task1");
-    /// ```
-    ///
-    /// Failure on invalid input:
-    /// ```
-    /// use maytrix_value::Symbol;
-    /// assert!(Symbol::try_new("1bad").is_err());
-    /// ```
-    pub fn try_new<S: Into<String>>(value: S) -> Result<Self, SymbolError> {
-        let s = value.into();
-        if Self::is_valid(&s) {
-            Ok(Self { value: s })
-        } else {
-            Err(SymbolError)
-        }
-    }
-
-    /// Returns the inner string slice.
-    ///
-    /// This is equivalent to dereferencing `Symbol` to `&str`.
-    ///
-    /// ```
-    /// use maytrix_value::Symbol;
-    /// let s = Symbol::try_new("ok").unwrap();
-    /// assert_eq!(s.as_str(), "ok");
-    /// assert_eq!(&*s, "ok"); // Deref to str
-    /// ```
-    pub fn as_str(&self) -> &str {
-        &self.value
-    }
-
-    /// Returns true if the provided string matches `^[a-z][a-z0-9_]*$`.
-    ///
-    /// This is a pure validator that does not allocate.
-    ///
-    /// ```
-    /// use maytrix_value::Symbol;
-    /// assert!(Symbol::is_valid("a"));
-    /// assert!(Symbol::is_valid("a0_b"));
-    /// assert!(!Symbol::is_valid("_bad"));
-    /// assert!(!Symbol::is_valid("Nope"));
-    /// ```
-    pub fn is_valid(s: &str) -> bool {
-        let mut chars = s.chars();
-        match chars.next() {
-            Some(first) if first.is_ascii_lowercase() => {}
-            _ => return false,
-        }
-        for c in chars {
-            if !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') {
-                return false;
-            }
-        }
-        true
-    }
-}
-
-impl core::fmt::Display for Symbol {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        self.value.fmt(f)
-    }
-}
-
-impl core::ops::Deref for Symbol {
-    type Target = str;
-    fn deref(&self) -> &Self::Target {
-        self.as_str()
-    }
-}
-
-impl core::str::FromStr for Symbol {
-    type Err = SymbolError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Symbol::try_new(s)
-    }
-}
-
-impl TryFrom<&str> for Symbol {
-    type Error = SymbolError;
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Symbol::try_new(value)
-    }
-}
-
-impl TryFrom<String> for Symbol {
-    type Error = SymbolError;
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        Symbol::try_new(value)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SymbolError;
-
-impl core::fmt::Display for SymbolError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "value must match ^[a-z][a-z0-9_]*$")
-    }
-}
-
-impl std::error::Error for SymbolError {}
-
-impl AsRef<str> for Symbol {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl core::borrow::Borrow<str> for Symbol {
-    fn borrow(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl core::cmp::PartialOrd for Symbol {
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        Some(self.as_str().cmp(other.as_str()))
-    }
-}
-
-impl core::cmp::Ord for Symbol {
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.as_str().cmp(other.as_str())
-    }
-}
-
-impl From<Symbol> for String {
-    fn from(s: Symbol) -> Self {
-        s.value
-    }
-}
-
-impl From<Symbol> for Box<str> {
-    fn from(s: Symbol) -> Self {
-        s.value.into_boxed_str()
-    }
-}
-
-// Optional ergonomic cross-type equality
-impl PartialEq<&str> for Symbol {
-    fn eq(&self, other: &&str) -> bool {
-        self.as_str() == *other
-    }
-}
-impl PartialEq<Symbol> for &str {
-    fn eq(&self, other: &Symbol) -> bool {
-        *self == other.as_str()
-    }
-}
-impl PartialEq<String> for Symbol {
-    fn eq(&self, other: &String) -> bool {
-        self.as_str() == other.as_str()
-    }
-}
-impl PartialEq<Symbol> for String {
-    fn eq(&self, other: &Symbol) -> bool {
-        self.as_str() == other.as_str()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    use core::str::FromStr;
-
-    #[test]
-    fn is_valid_accepts_simple_lowercase() {
-        assert!(Symbol::is_valid("a"));
-        assert!(Symbol::is_valid("abc"));
-        assert!(Symbol::is_valid("z"));
-    }
-
-    #[test]
-    fn is_valid_accepts_digits_and_underscores_after_first() {
-        assert!(Symbol::is_valid("a1"));
-        assert!(Symbol::is_valid("a_b"));
-        assert!(Symbol::is_valid("a1_b2_c3"));
-        assert!(Symbol::is_valid("a0_9"));
-        assert!(Symbol::is_valid("a__"));
-    }
-
-    #[test]
-    fn is_valid_rejects_empty_and_bad_first_char() {
-        assert!(!Symbol::is_valid(""));
-        assert!(!Symbol::is_valid("1abc"));
-        assert!(!Symbol::is_valid("_abc"));
-        assert!(!Symbol::is_valid("A"));
-    }
-
-    #[test]
-    fn is_valid_rejects_invalid_tail_chars() {
-        assert!(!Symbol::is_valid("a-"));
-        assert!(!Symbol::is_valid("a-1"));
-        assert!(!Symbol::is_valid("a b"));
-        assert!(!Symbol::is_valid("a$"));
-        assert!(!Symbol::is_valid("aB")); // uppercase after first not allowed either
-        assert!(!Symbol::is_valid("a√Ñ")); // non-ascii letter
-    }
-
-    #[test]
-    fn try_new_constructs_for_valid_and_errors_for_invalid() {
-        let ok = Symbol::try_new("abc_123");
-        assert!(ok.is_ok());
-        assert_eq!(ok.unwrap().as_str(), "abc_123");
-
-        let err = Symbol::try_new("-bad");
-        assert!(err.is_err());
-    }
-
-    #[test]
-    fn display_and_deref_expose_inner() {
-        let s = Symbol::try_new("abc_123").unwrap();
-        assert_eq!(&*s, "abc_123"); // Deref<str>
-        assert_eq!(s.as_str(), "abc_123");
-        assert_eq!(s.to_string(), "abc_123");
-    }
-
-    #[test]
-    fn from_str_and_try_from_work() {
-        let s1 = Symbol::from_str("name1").unwrap();
-        assert_eq!(s1, "name1");
-
-        let s2: Result<Symbol, _> = "x_y".try_into();
-        assert_eq!(s2.unwrap(), "x_y");
-
-        let s3: Result<Symbol, _> = String::from("ok_2").try_into();
-        assert_eq!(s3.unwrap(), "ok_2");
-
-        let bad: Result<Symbol, _> = "Nope".try_into();
-        assert!(bad.is_err());
-    }
-
-    #[test]
-    fn error_display_message_matches_spec() {
-        let err = Symbol::try_new("Bad-Name").unwrap_err();
-        assert_eq!(err.to_string(), "value must match ^[a-z][a-z0-9_]*$");
-    }
-
-    #[test]
-    fn equality_and_hash_semantics() {
-        use std::collections::HashSet;
-        let a = Symbol::try_new("abc").unwrap();
-        let b = Symbol::try_new("abc").unwrap();
-        let c = Symbol::try_new("abd").unwrap();
-
-        assert_eq!(a, b);
-        assert_ne!(a, c);
-
-        let mut set = HashSet::new();
-        set.insert(a);
-        assert!(set.contains(&b));
-        assert!(!set.contains(&c));
-        // Borrow<str> enables contains lookup by &str in HashSet as well
-        assert!(set.contains("abc"));
-        assert!(!set.contains("abd"));
-    }
-
-    #[test]
-    fn as_ref_borrow_and_hashmap_lookup() {
-        use std::collections::HashMap;
-        let key = Symbol::try_new("alpha").unwrap();
-        let mut map = HashMap::new();
-        map.insert(key.clone(), 42);
-        // Lookup by &str thanks to Borrow<str>
-        assert_eq!(map.get("alpha"), Some(&42));
-
-        // AsRef<str>
-        fn takes_as_ref<S: AsRef<str>>(s: S) -> usize { s.as_ref().len() }
-        assert_eq!(takes_as_ref(&key), 5);
-    }
-
-    #[test]
-    fn ordering_and_btreeset() {
-        use std::collections::BTreeSet;
-        let inputs = ["beta", "alpha", "alpha_1", "alpha0"];
-        let mut syms: Vec<Symbol> = inputs.iter().map(|s| Symbol::try_new(*s).unwrap()).collect();
-        syms.sort(); // requires PartialOrd/Ord
-        let sorted: Vec<&str> = syms.iter().map(|s| s.as_str()).collect();
-        assert_eq!(sorted, vec!["alpha", "alpha0", "alpha_1", "beta"]);
-
-        let set: BTreeSet<Symbol> = syms.into_iter().collect();
-        let ordered: Vec<&str> = set.iter().map(|s| s.as_str()).collect();
-        assert_eq!(ordered, vec!["alpha", "alpha0", "alpha_1", "beta"]);
-    }
-
-    #[test]
-    fn into_string_and_boxed_str() {
-        let s = Symbol::try_new("gamma").unwrap();
-        let owned: String = s.clone().into();
-        assert_eq!(owned, "gamma");
-        let boxed: Box<str> = s.clone().into();
-        assert_eq!(&*boxed, "gamma");
-    }
-
-    #[test]
-    fn cross_type_equality() {
-        let s = Symbol::try_new("delta_1").unwrap();
-        assert!(s == "delta_1");
-        assert!("delta_1" == s);
-        assert!(String::from("delta_1") == s);
-        assert!(s == String::from("delta_1"));
-        assert!(s != "delta2");
-    }
-}
+use core::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+use rustc_hash::FxHashMap;
+
+use crate::spec::LowerSnake;
+pub use crate::spec::{AsciiOnly, KebabCase, MaxLen, NumericCode, SymbolSpec, UpperCamel};
+
+/// Global pool of interned symbol strings, shared by every [`Symbol`].
+///
+/// Interning assigns each distinct, validated string a stable `u32` id the
+/// first time it is seen, so that [`Symbol`] can be `Copy` and compare,
+/// hash, and clone in O(1) instead of walking or copying bytes. Interned
+/// strings are leaked so they can be handed out as `&'static str` for the
+/// lifetime of the process. The pool is shared across every [`SymbolSpec`]:
+/// interning is purely about the text, not which ruleset validated it.
+struct SymbolInterner {
+    by_name: RwLock<FxHashMap<Box<str>, u32>>,
+    by_id: RwLock<Vec<&'static str>>,
+}
+
+impl SymbolInterner {
+    fn global() -> &'static SymbolInterner {
+        static INTERNER: OnceLock<SymbolInterner> = OnceLock::new();
+        INTERNER.get_or_init(|| SymbolInterner {
+            by_name: RwLock::new(FxHashMap::default()),
+            by_id: RwLock::new(Vec::new()),
+        })
+    }
+
+    fn intern(&self, s: &str) -> u32 {
+        if let Some(&id) = self.by_name.read().unwrap().get(s) {
+            return id;
+        }
+        let mut by_name = self.by_name.write().unwrap();
+        // Another thread may have interned `s` while we were waiting for the write lock.
+        if let Some(&id) = by_name.get(s) {
+            return id;
+        }
+        let leaked: &'static str = Box::leak(s.to_owned().into_boxed_str());
+        let mut by_id = self.by_id.write().unwrap();
+        let id = by_id.len() as u32;
+        by_id.push(leaked);
+        by_name.insert(Box::from(leaked), id);
+        id
+    }
+
+    fn resolve(&self, id: u32) -> &'static str {
+        self.by_id.read().unwrap()[id as usize]
+    }
+}
+
+/// A validated identifier, by default following the pattern
+/// `^[a-z][a-z0-9_]*$`.
+///
+/// `Symbol` ensures its inner value is well-formed according to a
+/// [`SymbolSpec`] ruleset `S` (the [`LowerSnake`] rule by default), commonly
+/// used for names, keys, or codes. Values are interned in a global pool
+/// (see [`Symbol::intern`]/[`Symbol::resolve`]), so `Symbol` itself is just
+/// a `u32` id plus a `u64` gensym tag: it is `Copy`, and equality is O(1)
+/// instead of walking the underlying bytes. Hashing still hashes the text,
+/// to agree with `str` for `Borrow<str>` lookups. It still implements `Eq`,
+/// `Ord`, and `Hash` for map/set usage and supports borrowing as `&str`.
+///
+/// Other rulesets live behind the same type, parameterized by `S`, e.g.
+/// `Symbol<UpperCamel>` or `Symbol<KebabCase>` — see [`Symbol::try_new_with`].
+///
+/// # Examples
+///
+/// Creating a valid `Symbol`:
+///
+/// ```
+/// use maytrix_value::Symbol;
+/// let sym = Symbol::try_new("alpha_1").unwrap();
+/// assert_eq!(sym.as_str(), "alpha_1");
+/// ```
+///
+/// Invalid values yield an error:
+///
+/// ```
+/// use maytrix_value::Symbol;
+/// assert!(Symbol::try_new("Bad-Name").is_err());
+/// ```
+///
+/// Building under a different ruleset:
+///
+/// ```
+/// use maytrix_value::{Symbol, UpperCamel};
+/// let ty = Symbol::try_new_with(UpperCamel, "MyType").unwrap();
+/// assert_eq!(ty.as_str(), "MyType");
+/// assert!(Symbol::try_new_with(UpperCamel, "my_type").is_err());
+/// ```
+pub struct Symbol<S: SymbolSpec = LowerSnake> {
+    id: u32,
+    /// Zero for an ordinary, `try_new`-constructed symbol. Non-zero for a
+    /// [`Symbol::gensym`] symbol, where it is a process-unique tag that
+    /// keeps the symbol distinct from every other symbol printing the same
+    /// text, gensym or not. Also folded into `Hash` (see the `Hash` impl)
+    /// so a gensym isn't silently bucketed with a same-text symbol for a
+    /// `Borrow<str>` lookup.
+    fresh: u64,
+    _spec: PhantomData<S>,
+}
+
+impl<S: SymbolSpec> Symbol<S> {
+    /// Attempts to construct a `Symbol<S>` from a string-like value,
+    /// validating it against `S` (a [`SymbolSpec`] instance, passed by
+    /// value purely so `S` can be inferred without a turbofish).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maytrix_value::{Symbol, KebabCase};
+    /// let s = Symbol::try_new_with(KebabCase, "my-key").unwrap();
+    /// assert_eq!(s.as_str(), "my-key");
+    /// assert!(Symbol::try_new_with(KebabCase, "My-Key").is_err());
+    /// ```
+    pub fn try_new_with<V: Into<String>>(_spec: S, value: V) -> Result<Self, SymbolError> {
+        let s = value.into();
+        if S::is_valid(&s) {
+            Ok(Self {
+                id: SymbolInterner::global().intern(&s),
+                fresh: 0,
+                _spec: PhantomData,
+            })
+        } else {
+            Err(SymbolError::for_spec::<S>())
+        }
+    }
+
+    /// Returns the inner string slice.
+    ///
+    /// This is equivalent to dereferencing `Symbol` to `&str`.
+    ///
+    /// ```
+    /// use maytrix_value::Symbol;
+    /// let s = Symbol::try_new("ok").unwrap();
+    /// assert_eq!(s.as_str(), "ok");
+    /// assert_eq!(&*s, "ok"); // Deref to str
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        SymbolInterner::global().resolve(self.id)
+    }
+
+    /// Returns true if this symbol was produced by [`Symbol::gensym`].
+    pub fn is_gensym(&self) -> bool {
+        self.fresh != 0
+    }
+}
+
+impl Symbol<LowerSnake> {
+    /// Attempts to construct a `Symbol` from a string-like value.
+    ///
+    /// The input must match the regex `^[a-z][a-z0-9_]*$`. On success the
+    /// value is interned into the global pool, and every subsequent `Symbol`
+    /// built from the same text reuses its id.
+    ///
+    /// # Examples
+    ///
+    /// Successful creation:
+    /// ```
+    /// use maytrix_value::Symbol;
+    /// let s = Symbol::try_new("task1").unwrap();
+    /// assert_eq!(s, "task1");
+    /// ```
+    ///
+    /// Failure on invalid input:
+    /// ```
+    /// use maytrix_value::Symbol;
+    /// assert!(Symbol::try_new("1bad").is_err());
+    /// ```
+    pub fn try_new<V: Into<String>>(value: V) -> Result<Self, SymbolError> {
+        Self::try_new_with(LowerSnake, value)
+    }
+
+    /// Produces a fresh symbol guaranteed never to equal any other symbol,
+    /// gensym or not, no matter what text they print as.
+    ///
+    /// This mirrors rustc's `Ident::gensym`/`Symbol::gensym`: it is meant for
+    /// code generators and macro-expansion-style passes that need names
+    /// which can never collide with a user-written symbol. `base` is
+    /// sanitized into a valid `Symbol` prefix (lowercased, with any
+    /// character outside `[a-z0-9_]` replaced by `_`, and a leading `g`
+    /// inserted if the result wouldn't otherwise start with a lowercase
+    /// letter) and suffixed with a counter drawn from a global `AtomicU64`,
+    /// so the printed form still matches `^[a-z][a-z0-9_]*$`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maytrix_value::Symbol;
+    /// let a = Symbol::gensym("tmp");
+    /// let b = Symbol::gensym("tmp");
+    /// assert!(a.is_gensym());
+    /// assert_ne!(a, b); // same base, distinct identity
+    /// assert!(Symbol::is_valid(a.as_str()));
+    /// ```
+    pub fn gensym(base: &str) -> Symbol {
+        static COUNTER: AtomicU64 = AtomicU64::new(1);
+        let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let text = format!("{}_{counter}", sanitize_gensym_base(base));
+        debug_assert!(Self::is_valid(&text));
+        Symbol {
+            id: Symbol::intern(&text),
+            fresh: counter,
+            _spec: PhantomData,
+        }
+    }
+
+    /// Returns true if the provided string matches `^[a-z][a-z0-9_]*$`.
+    ///
+    /// This is a pure validator that does not allocate.
+    ///
+    /// ```
+    /// use maytrix_value::Symbol;
+    /// assert!(Symbol::is_valid("a"));
+    /// assert!(Symbol::is_valid("a0_b"));
+    /// assert!(!Symbol::is_valid("_bad"));
+    /// assert!(!Symbol::is_valid("Nope"));
+    /// ```
+    pub fn is_valid(s: &str) -> bool {
+        LowerSnake::is_valid(s)
+    }
+
+    /// Interns `s` in the global pool, returning its stable id.
+    ///
+    /// Calling this again with an already-interned string returns the same
+    /// id; the id is stable for the remaining lifetime of the process. The
+    /// pool is shared across every [`SymbolSpec`], so this is also what
+    /// [`Symbol::try_new_with`] uses under the hood for other specs. This
+    /// does not validate `s` against any ruleset.
+    pub fn intern(s: &str) -> u32 {
+        SymbolInterner::global().intern(s)
+    }
+
+    /// Resolves a previously interned id back to its string.
+    ///
+    /// Returns `None` if `id` was never produced by [`Symbol::intern`].
+    pub fn resolve(id: u32) -> Option<&'static str> {
+        let by_id = SymbolInterner::global().by_id.read().unwrap();
+        by_id.get(id as usize).copied()
+    }
+
+    /// Interns every string in `values` up front.
+    ///
+    /// Useful to pre-seed the pool with well-known symbols so their ids are
+    /// assigned deterministically (e.g. in the same order across runs)
+    /// before application code starts interning ad hoc names.
+    pub fn seed<I: IntoIterator<Item = V>, V: AsRef<str>>(values: I) {
+        for value in values {
+            Self::intern(value.as_ref());
+        }
+    }
+}
+
+/// Lowercases `base` and replaces any character outside `[a-z0-9_]` with
+/// `_`, then, if the result still wouldn't start with a lowercase letter
+/// (e.g. it was empty or began with a digit), prefixes a `g`. Used by
+/// [`Symbol::gensym`] to turn an arbitrary base into a valid `Symbol`
+/// prefix before the counter suffix is appended.
+fn sanitize_gensym_base(base: &str) -> String {
+    let mut out: String = base
+        .chars()
+        .map(|c| {
+            let lower = c.to_ascii_lowercase();
+            if lower.is_ascii_lowercase() || lower.is_ascii_digit() || lower == '_' {
+                lower
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !matches!(out.chars().next(), Some(c) if c.is_ascii_lowercase()) {
+        out.insert(0, 'g');
+    }
+    out
+}
+
+impl<S: SymbolSpec> Clone for Symbol<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: SymbolSpec> Copy for Symbol<S> {}
+
+impl<S: SymbolSpec> core::fmt::Debug for Symbol<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Symbol").field("value", &self.as_str()).finish()
+    }
+}
+
+impl<S: SymbolSpec> core::fmt::Display for Symbol<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<S: SymbolSpec> core::ops::Deref for Symbol<S> {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl core::str::FromStr for Symbol {
+    type Err = SymbolError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Symbol::try_new(s)
+    }
+}
+
+impl TryFrom<&str> for Symbol {
+    type Error = SymbolError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Symbol::try_new(value)
+    }
+}
+
+impl TryFrom<String> for Symbol {
+    type Error = SymbolError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Symbol::try_new(value)
+    }
+}
+
+/// The error returned when a value fails a [`SymbolSpec`] ruleset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolError {
+    spec_name: &'static str,
+    pattern: &'static str,
+}
+
+impl SymbolError {
+    fn for_spec<S: SymbolSpec>() -> Self {
+        Self {
+            spec_name: S::NAME,
+            pattern: S::pattern(),
+        }
+    }
+
+    /// The name of the [`SymbolSpec`] that rejected the value, e.g. `"lower_snake"`.
+    pub fn spec_name(&self) -> &'static str {
+        self.spec_name
+    }
+
+    /// The pattern of the [`SymbolSpec`] that rejected the value.
+    pub fn pattern(&self) -> &'static str {
+        self.pattern
+    }
+}
+
+impl core::fmt::Display for SymbolError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "value must match {}", self.pattern)
+    }
+}
+
+impl std::error::Error for SymbolError {}
+
+impl<S: SymbolSpec> AsRef<str> for Symbol<S> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<S: SymbolSpec> core::borrow::Borrow<str> for Symbol<S> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<S: SymbolSpec> PartialEq for Symbol<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.fresh == other.fresh
+    }
+}
+
+impl<S: SymbolSpec> Eq for Symbol<S> {}
+
+impl<S: SymbolSpec> core::hash::Hash for Symbol<S> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // For an ordinary symbol (`fresh == 0`) this must match `str`'s
+        // `Hash` impl bit-for-bit, or `Borrow<str>` lookups in
+        // `HashSet`/`HashMap` (hash by `&str`, then compare candidates)
+        // break silently. Interning dedupes purely on text, so a gensym's
+        // generated text can coincide with some other symbol's — `Eq`
+        // correctly tells them apart via `fresh`, but if `Hash` only ever
+        // looked at text, the two would land in the same bucket and a
+        // `&str`-keyed lookup could resolve to either one arbitrarily. So a
+        // gensym (`fresh != 0`) folds `fresh` in too, deliberately hashing
+        // differently from its own printed text: gensyms are not meant to
+        // be found again via a `Borrow<str>` lookup, only via the `Symbol`
+        // handle returned by `gensym` itself.
+        self.as_str().hash(state);
+        if self.fresh != 0 {
+            self.fresh.hash(state);
+        }
+    }
+}
+
+impl<S: SymbolSpec> core::cmp::PartialOrd for Symbol<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: SymbolSpec> core::cmp::Ord for Symbol<S> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // Ids are assigned in intern order, not lexicographic order, so
+        // ordering compares the resolved strings (as ordering did before
+        // interning), falling back to `fresh` so the order
+        // stays consistent with `Eq` for otherwise-identical-looking gensyms.
+        self.as_str().cmp(other.as_str()).then(self.fresh.cmp(&other.fresh))
+    }
+}
+
+impl<S: SymbolSpec> From<Symbol<S>> for String {
+    fn from(s: Symbol<S>) -> Self {
+        s.as_str().to_owned()
+    }
+}
+
+impl<S: SymbolSpec> From<Symbol<S>> for Box<str> {
+    fn from(s: Symbol<S>) -> Self {
+        Box::from(s.as_str())
+    }
+}
+
+// Optional ergonomic cross-type equality
+impl<S: SymbolSpec> PartialEq<&str> for Symbol<S> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+impl<S: SymbolSpec> PartialEq<Symbol<S>> for &str {
+    fn eq(&self, other: &Symbol<S>) -> bool {
+        *self == other.as_str()
+    }
+}
+impl<S: SymbolSpec> PartialEq<String> for Symbol<S> {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl<S: SymbolSpec> PartialEq<Symbol<S>> for String {
+    fn eq(&self, other: &Symbol<S>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    #[test]
+    fn is_valid_accepts_simple_lowercase() {
+        assert!(Symbol::is_valid("a"));
+        assert!(Symbol::is_valid("abc"));
+        assert!(Symbol::is_valid("z"));
+    }
+
+    #[test]
+    fn is_valid_accepts_digits_and_underscores_after_first() {
+        assert!(Symbol::is_valid("a1"));
+        assert!(Symbol::is_valid("a_b"));
+        assert!(Symbol::is_valid("a1_b2_c3"));
+        assert!(Symbol::is_valid("a0_9"));
+        assert!(Symbol::is_valid("a__"));
+    }
+
+    #[test]
+    fn is_valid_rejects_empty_and_bad_first_char() {
+        assert!(!Symbol::is_valid(""));
+        assert!(!Symbol::is_valid("1abc"));
+        assert!(!Symbol::is_valid("_abc"));
+        assert!(!Symbol::is_valid("A"));
+    }
+
+    #[test]
+    fn is_valid_rejects_invalid_tail_chars() {
+        assert!(!Symbol::is_valid("a-"));
+        assert!(!Symbol::is_valid("a-1"));
+        assert!(!Symbol::is_valid("a b"));
+        assert!(!Symbol::is_valid("a$"));
+        assert!(!Symbol::is_valid("aB")); // uppercase after first not allowed either
+        assert!(!Symbol::is_valid("a√Ñ")); // non-ascii letter
+    }
+
+    #[test]
+    fn try_new_constructs_for_valid_and_errors_for_invalid() {
+        let ok = Symbol::try_new("abc_123");
+        assert!(ok.is_ok());
+        assert_eq!(ok.unwrap().as_str(), "abc_123");
+
+        let err = Symbol::try_new("-bad");
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn display_and_deref_expose_inner() {
+        let s = Symbol::try_new("abc_123").unwrap();
+        assert_eq!(&*s, "abc_123"); // Deref<str>
+        assert_eq!(s.as_str(), "abc_123");
+        assert_eq!(s.to_string(), "abc_123");
+    }
+
+    #[test]
+    fn from_str_and_try_from_work() {
+        let s1 = Symbol::from_str("name1").unwrap();
+        assert_eq!(s1, "name1");
+
+        let s2: Result<Symbol, _> = "x_y".try_into();
+        assert_eq!(s2.unwrap(), "x_y");
+
+        let s3: Result<Symbol, _> = String::from("ok_2").try_into();
+        assert_eq!(s3.unwrap(), "ok_2");
+
+        let bad: Result<Symbol, _> = "Nope".try_into();
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn error_display_message_matches_spec() {
+        let err = Symbol::try_new("Bad-Name").unwrap_err();
+        assert_eq!(err.to_string(), "value must match ^[a-z][a-z0-9_]*$");
+    }
+
+    #[test]
+    fn equality_and_hash_semantics() {
+        use std::collections::HashSet;
+        let a = Symbol::try_new("abc").unwrap();
+        let b = Symbol::try_new("abc").unwrap();
+        let c = Symbol::try_new("abd").unwrap();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+        assert!(!set.contains(&c));
+        // Borrow<str> enables contains lookup by &str in HashSet as well
+        assert!(set.contains("abc"));
+        assert!(!set.contains("abd"));
+    }
+
+    #[test]
+    fn as_ref_borrow_and_hashmap_lookup() {
+        use std::collections::HashMap;
+        let key = Symbol::try_new("alpha").unwrap();
+        let mut map = HashMap::new();
+        map.insert(key, 42);
+        // Lookup by &str thanks to Borrow<str>
+        assert_eq!(map.get("alpha"), Some(&42));
+
+        // AsRef<str>
+        fn takes_as_ref<S: AsRef<str>>(s: S) -> usize {
+            s.as_ref().len()
+        }
+        assert_eq!(takes_as_ref(key), 5);
+    }
+
+    #[test]
+    fn ordering_and_btreeset() {
+        use std::collections::BTreeSet;
+        let inputs = ["beta", "alpha", "alpha_1", "alpha0"];
+        let mut syms: Vec<Symbol> = inputs.iter().map(|s| Symbol::try_new(*s).unwrap()).collect();
+        syms.sort(); // requires PartialOrd/Ord
+        let sorted: Vec<&str> = syms.iter().map(|s| s.as_str()).collect();
+        assert_eq!(sorted, vec!["alpha", "alpha0", "alpha_1", "beta"]);
+
+        let set: BTreeSet<Symbol> = syms.into_iter().collect();
+        let ordered: Vec<&str> = set.iter().map(|s| s.as_str()).collect();
+        assert_eq!(ordered, vec!["alpha", "alpha0", "alpha_1", "beta"]);
+    }
+
+    #[test]
+    fn into_string_and_boxed_str() {
+        let s = Symbol::try_new("gamma").unwrap();
+        let owned: String = s.into();
+        assert_eq!(owned, "gamma");
+        let boxed: Box<str> = s.into();
+        assert_eq!(&*boxed, "gamma");
+    }
+
+    #[test]
+    fn cross_type_equality() {
+        let s = Symbol::try_new("delta_1").unwrap();
+        assert!(s == "delta_1");
+        assert!("delta_1" == s);
+        assert!(String::from("delta_1") == s);
+        assert!(s == String::from("delta_1"));
+        assert!(s != "delta2");
+    }
+
+    #[test]
+    fn is_copy() {
+        let s = Symbol::try_new("epsilon").unwrap();
+        let t = s; // Copy, not move
+        assert_eq!(s, t);
+    }
+
+    #[test]
+    fn interning_reuses_ids_for_equal_strings() {
+        let a = Symbol::try_new("zeta_interned").unwrap();
+        let b = Symbol::try_new("zeta_interned").unwrap();
+        assert_eq!(a.id, b.id);
+        assert_eq!(Symbol::intern("zeta_interned"), a.id);
+    }
+
+    #[test]
+    fn resolve_round_trips_interned_strings() {
+        let id = Symbol::intern("eta_resolve_me");
+        assert_eq!(Symbol::resolve(id), Some("eta_resolve_me"));
+    }
+
+    #[test]
+    fn seed_preinterns_without_erroring() {
+        Symbol::seed(["theta_seed_a", "theta_seed_b"]);
+        let s = Symbol::try_new("theta_seed_a").unwrap();
+        assert_eq!(s.as_str(), "theta_seed_a");
+    }
+
+    #[test]
+    fn gensym_is_collision_free_across_same_base() {
+        let a = Symbol::gensym("tmp");
+        let b = Symbol::gensym("tmp");
+        assert!(a.is_gensym());
+        assert!(b.is_gensym());
+        assert_ne!(a, b);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(a);
+        assert!(!set.contains(&b));
+    }
+
+    #[test]
+    fn gensym_stays_distinct_from_ordinary_symbol_with_same_text() {
+        let gen = Symbol::gensym("iota");
+        let ordinary = Symbol::try_new(gen.as_str()).unwrap();
+        assert!(gen.is_gensym());
+        assert!(!ordinary.is_gensym());
+        assert_ne!(gen, ordinary);
+    }
+
+    #[test]
+    fn gensym_does_not_shadow_a_same_text_symbol_in_a_borrow_str_lookup() {
+        use std::collections::HashSet;
+        let gen = Symbol::gensym("probe");
+        let ordinary = Symbol::try_new(gen.as_str()).unwrap();
+        assert_ne!(gen, ordinary);
+
+        let mut set = HashSet::new();
+        set.insert(gen);
+        set.insert(ordinary);
+        assert_eq!(set.len(), 2);
+        // The gensym deliberately hashes differently from its own text, so a
+        // `&str` lookup can only ever resolve to the ordinary symbol.
+        assert_eq!(set.get(gen.as_str()), Some(&ordinary));
+    }
+
+    #[test]
+    fn gensym_renders_a_valid_looking_name() {
+        for base in ["tmp", "Bad-Base", "123start", "_underscored", ""] {
+            let sym = Symbol::gensym(base);
+            assert!(Symbol::is_valid(sym.as_str()), "{:?} not valid", sym.as_str());
+        }
+    }
+
+    #[test]
+    fn ordinary_symbols_are_not_gensyms() {
+        let s = Symbol::try_new("kappa").unwrap();
+        assert!(!s.is_gensym());
+    }
+
+    #[test]
+    fn try_new_with_builds_under_an_alternate_spec() {
+        let ty = Symbol::try_new_with(UpperCamel, "MyType").unwrap();
+        assert_eq!(ty.as_str(), "MyType");
+        assert!(Symbol::try_new_with(UpperCamel, "my_type").is_err());
+
+        let key = Symbol::try_new_with(KebabCase, "my-key").unwrap();
+        assert_eq!(key.as_str(), "my-key");
+
+        let code = Symbol::try_new_with(NumericCode, "007_bond").unwrap();
+        assert_eq!(code.as_str(), "007_bond");
+    }
+
+    #[test]
+    fn symbol_error_reports_spec_name_and_pattern() {
+        let err = Symbol::try_new_with(UpperCamel, "nope").unwrap_err();
+        assert_eq!(err.spec_name(), "upper_camel");
+        assert_eq!(err.pattern(), UpperCamel::pattern());
+    }
+
+    #[test]
+    fn different_specs_share_the_interning_pool() {
+        let lower_snake = Symbol::try_new("foo").unwrap();
+        let kebab = Symbol::try_new_with(KebabCase, "foo").unwrap();
+        assert_eq!(lower_snake.id, kebab.id);
+        assert_eq!(Symbol::intern("foo"), lower_snake.id);
+    }
+}